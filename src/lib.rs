@@ -7,6 +7,16 @@ use std::iter::FusedIterator;
 pub struct FullyPeekableIterator<I: Iterator> {
     iter: I,
     queue: VecDeque<I::Item>,
+    /// The number of items at the back of `queue` that were pulled via `iter.next_back()`, i.e.
+    /// the suffix of `queue` that is confirmed to sit at the true tail of the stream. The
+    /// remaining `queue.len() - back_len` items at the front were pulled via `iter.next()` and
+    /// are confirmed to sit at the true front of the stream. Together these two runs are kept in
+    /// stream order, so `queue` never has to be reordered as items are consumed from either end.
+    back_len: usize,
+    /// The advancing cursor used by `peek_next`, indexing into `queue` from the front. It tracks
+    /// how far ahead of the real front we've peeked using the stateful cursor API, and is reset
+    /// to zero by any call to `next`/`next_back` since those move the real front/back instead.
+    cursor: usize,
 }
 
 /// Create a new fully-peekable iterator from an existing iterator.
@@ -15,8 +25,17 @@ impl<I: Iterator> FullyPeekableIterator<I> {
         FullyPeekableIterator {
             iter,
             queue: VecDeque::new(),
+            back_len: 0,
+            cursor: 0,
         }
     }
+
+    /// The index just past the front-pulled run of `queue`, i.e. where a newly front-pulled or
+    /// back-pulled item must be inserted to keep `queue` in stream order.
+    #[inline]
+    fn front_len(&self) -> usize {
+        self.queue.len() - self.back_len
+    }
 }
 
 /// Implementation of the typical iterator methods on the fully-peekable iterator.
@@ -26,7 +45,15 @@ impl<I: Iterator> Iterator for FullyPeekableIterator<I> {
     /// Returns the next value which may advance the iterator.
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.queue.pop_front().or_else(|| self.iter.next())
+        self.cursor = 0;
+        if self.front_len() > 0 {
+            self.queue.pop_front()
+        } else {
+            self.iter.next().or_else(|| {
+                self.back_len = self.back_len.saturating_sub(1);
+                self.queue.pop_front()
+            })
+        }
     }
 
     /// Returns the bounds on the remaining length of the iterator.
@@ -41,9 +68,117 @@ impl<I: Iterator> Iterator for FullyPeekableIterator<I> {
         };
         (lo, hi)
     }
+
+    /// Returns the `n`th value, skipping the rest, which may advance the iterator.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.cursor = 0;
+        if self.back_len == 0 {
+            let front_len = self.queue.len();
+            if n < front_len {
+                self.queue.drain(..n);
+                self.queue.pop_front()
+            } else {
+                self.queue.clear();
+                self.iter.nth(n - front_len)
+            }
+        } else {
+            // Rare combination of `nth` with buffered back-peeks: fall back to stepping through
+            // `next`, which already knows how to cross the front/back boundary correctly.
+            for _ in 0..n {
+                self.next()?;
+            }
+            self.next()
+        }
+    }
+
+    /// Consumes the iterator, counting the number of iterations and returning it.
+    #[inline]
+    fn count(self) -> usize {
+        self.queue.len() + self.iter.count()
+    }
+
+    /// Consumes the iterator, returning the last element.
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        if self.back_len > 0 {
+            self.queue.into_iter().last()
+        } else {
+            self.iter.last().or_else(|| self.queue.into_iter().last())
+        }
+    }
+
+    /// Folds every element into an accumulator, honoring the front-of-queue-first ordering so
+    /// that buffered peeks are folded in before falling through to the underlying iterator's own
+    /// (potentially specialized) `fold`, with any buffered back-peeks folded in last.
+    ///
+    /// `try_fold` is not specialized here: overriding it requires naming `std::ops::Try` in the
+    /// method signature, which is still gated behind the unstable `try_trait_v2` feature, so this
+    /// type falls back to the default `try_fold` (built on `next`) until that trait stabilizes.
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let front_len = self.front_len();
+        let mut queue = self.queue;
+        let mut acc = init;
+        for item in queue.drain(..front_len) {
+            acc = f(acc, item);
+        }
+        acc = self.iter.fold(acc, &mut f);
+        for item in queue {
+            acc = f(acc, item);
+        }
+        acc
+    }
 }
 
-// TODO: Implement `DoubleEndedIterator` for `FullyPeekableIterator`?
+/// Implementation of `DoubleEndedIterator` for the fully-peekable iterator.
+///
+/// `queue` holds a front-pulled run and a back-pulled run, kept in stream order, so popping from
+/// either end always yields the item genuinely adjacent to that end: once a run is empty, the
+/// corresponding end defers straight to `iter`, which still tracks the true boundary on that
+/// side for whatever hasn't been buffered yet. A front peek and a back peek of the same run can
+/// therefore never double-yield an element; once `queue` holds every remaining item the two ends
+/// simply meet in the middle.
+impl<I: DoubleEndedIterator> DoubleEndedIterator for FullyPeekableIterator<I> {
+    /// Returns the next value from the back which may advance the iterator.
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cursor = 0;
+        if self.back_len > 0 {
+            self.back_len -= 1;
+            self.queue.pop_back()
+        } else {
+            self.iter.next_back().or_else(|| self.queue.pop_back())
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> FullyPeekableIterator<I> {
+    /// Peek backward to an arbitrary element without advancing the iterator.
+    #[inline]
+    pub fn lift_back(&mut self, index: usize) -> Option<&I::Item> {
+        while self.back_len < index + 1 {
+            match self.iter.next_back() {
+                Some(item) => {
+                    let at = self.front_len();
+                    self.queue.insert(at, item);
+                    self.back_len += 1;
+                }
+                None => break,
+            }
+        }
+        self.queue.len().checked_sub(index + 1).and_then(|i| self.queue.get(i))
+    }
+
+    /// Peek backward to the next element without advancing the iterator.
+    #[inline]
+    pub fn peek_back(&mut self) -> Option<&I::Item> {
+        self.lift_back(0)
+    }
+}
 
 impl<I: ExactSizeIterator> ExactSizeIterator for FullyPeekableIterator<I> {}
 
@@ -59,9 +194,12 @@ impl<I: Iterator> FullyPeekableIterator<I> {
     /// Peek forward to an arbitrary element without advancing the iterator.
     #[inline]
     pub fn lift(&mut self, index: usize) -> Option<&I::Item> {
-        while self.queue.len() < index + 1 {
+        while self.front_len() < index + 1 {
             match self.iter.next() {
-                Some(item) => self.queue.push_back(item),
+                Some(item) => {
+                    let at = self.front_len();
+                    self.queue.insert(at, item);
+                }
                 None => break,
             }
         }
@@ -82,9 +220,12 @@ impl<I: Iterator> FullyPeekableIterator<I> {
     /// Peek forward to an arbitrary mutable element without advancing the iterator.
     #[inline]
     pub fn lift_mut(&mut self, index: usize) -> Option<&mut I::Item> {
-        while self.queue.len() <= index + 1 {
+        while self.front_len() <= index + 1 {
             match self.iter.next() {
-                Some(item) => self.queue.push_back(item),
+                Some(item) => {
+                    let at = self.front_len();
+                    self.queue.insert(at, item);
+                }
                 None => break,
             }
         }
@@ -109,11 +250,29 @@ impl<I: Iterator> FullyPeekableIterator<I> {
         self.lift_mut(0)
     }
 
-    /// Consume and return the next value of this iterator if a condition is true.
+    /// Peek forward to the next element, advancing an internal peek cursor so repeated calls walk
+    /// forward through the stream one element at a time without consuming anything. Call
+    /// `reset_peek` to rewind the cursor back to the front.
     #[inline]
-    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+    pub fn peek_next(&mut self) -> Option<&I::Item> {
+        let index = self.cursor;
+        self.cursor += 1;
+        self.lift(index)
+    }
+
+    /// Rewind the peek cursor set up by `peek_next` back to the front without consuming anything.
+    #[inline]
+    pub fn reset_peek(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Consume and return the next value of this iterator if a condition is true, leaving it
+    /// buffered at the front if the condition is false. This is the single-step primitive that
+    /// both `next_if` and `peeking_take_while` build on.
+    #[inline]
+    pub fn peeking_next(&mut self, accept: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
         match self.next() {
-            Some(matched) if func(&matched) => Some(matched),
+            Some(matched) if accept(&matched) => Some(matched),
             Some(other) => {
                 self.queue.push_front(other);
                 None
@@ -122,6 +281,12 @@ impl<I: Iterator> FullyPeekableIterator<I> {
         }
     }
 
+    /// Consume and return the next value of this iterator if a condition is true.
+    #[inline]
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        self.peeking_next(func)
+    }
+
     /// Consume and return the next item if it is equal to `expected`.
     #[inline]
     pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
@@ -131,6 +296,40 @@ impl<I: Iterator> FullyPeekableIterator<I> {
     {
         self.next_if(|next| next == expected)
     }
+
+    /// Yield items from the front of the stream as long as `pred` holds, stopping without
+    /// consuming the first item that fails the predicate, which remains available for the next
+    /// `peek`/`next`. The returned adaptor borrows `self`, so it can be used to drive several
+    /// non-destructive, conditional passes over the same stream in sequence.
+    #[inline]
+    pub fn peeking_take_while<P>(&mut self, pred: P) -> PeekingTakeWhile<'_, I, P>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        PeekingTakeWhile { iter: self, pred }
+    }
+}
+
+/// A non-destructive, conditional-consumption adaptor returned by `peeking_take_while`.
+pub struct PeekingTakeWhile<'a, I: Iterator, P> {
+    iter: &'a mut FullyPeekableIterator<I>,
+    pred: P,
+}
+
+/// Implementation of the typical iterator methods on the peeking-take-while adaptor.
+impl<'a, I, P> Iterator for PeekingTakeWhile<'a, I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    /// Returns the next value which may advance the underlying fully-peekable iterator.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.iter.peeking_next(|item| pred(item))
+    }
 }
 
 /// A trait for an iterator which allows you to fully peek forward any number of elements.
@@ -192,6 +391,44 @@ mod tests {
         assert_eq!(peekable.size_hint(), (0, Some(0)));
     }
 
+    #[test]
+    fn it_can_skip_ahead_to_the_nth_element() {
+        let iter = vec![1, 2, 3, 4].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.nth(1), Some(2));
+        assert_eq!(peekable.nth(1), Some(4));
+        assert_eq!(peekable.next(), None);
+    }
+
+    #[test]
+    fn it_counts_buffered_and_unbuffered_elements_together() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.count(), 3);
+    }
+
+    #[test]
+    fn it_returns_the_last_element_honoring_buffered_peeks() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.last(), Some(3));
+    }
+
+    #[test]
+    fn it_folds_buffered_and_unbuffered_elements_in_order() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        let order = peekable.fold(Vec::new(), |mut acc, item| {
+            acc.push(item);
+            acc
+        });
+        assert_eq!(order, vec![1, 2, 3]);
+    }
+
     #[test]
     fn it_can_estimate_a_size_even_if_the_iterator_has_no_high() {
         struct TestIterator<I: Iterator> {
@@ -285,6 +522,35 @@ mod tests {
         assert_eq!(peekable.peek_many(0), vec!());
     }
 
+    #[test]
+    fn it_can_walk_forward_with_an_advancing_peek_cursor() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = iter.fully_peekable();
+        assert_eq!(peekable.peek_next(), Some(&1));
+        assert_eq!(peekable.peek_next(), Some(&2));
+        assert_eq!(peekable.peek_next(), Some(&3));
+        assert_eq!(peekable.peek_next(), None);
+        assert_eq!(peekable.next(), Some(1));
+        assert_eq!(peekable.next(), Some(2));
+        assert_eq!(peekable.next(), Some(3));
+        assert_eq!(peekable.next(), None);
+    }
+
+    #[test]
+    fn it_can_reset_the_peek_cursor_back_to_the_front() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = iter.fully_peekable();
+        assert_eq!(peekable.peek_next(), Some(&1));
+        assert_eq!(peekable.peek_next(), Some(&2));
+        peekable.reset_peek();
+        assert_eq!(peekable.peek_next(), Some(&1));
+        assert_eq!(peekable.next(), Some(1));
+        assert_eq!(peekable.peek_next(), Some(&2));
+        assert_eq!(peekable.peek_next(), Some(&3));
+        assert_eq!(peekable.next(), Some(2));
+        assert_eq!(peekable.peek_next(), Some(&3));
+    }
+
     #[test]
     fn it_can_lift_elements_without_advancing_mut() {
         let iter = vec![1, 2].into_iter();
@@ -331,4 +597,161 @@ mod tests {
         assert_eq!(peekable.next_if_eq(&2), Some(2));
         assert_eq!(peekable.has_next(), false);
     }
+
+    #[test]
+    fn it_can_consume_the_next_element_using_peeking_next() {
+        let iter = vec![1, 2].into_iter();
+        let mut peekable = iter.fully_peekable();
+        assert_eq!(peekable.peeking_next(|next| next == &0), None);
+        assert_eq!(peekable.peeking_next(|next| next == &1), Some(1));
+        assert_eq!(peekable.peeking_next(|next| next == &1), None);
+        assert_eq!(peekable.peeking_next(|next| next == &2), Some(2));
+        assert_eq!(peekable.has_next(), false);
+    }
+
+    #[test]
+    fn it_can_take_a_run_of_elements_while_a_predicate_holds() {
+        let iter = vec![1, 2, 3, 10, 4].into_iter();
+        let mut peekable = iter.fully_peekable();
+        let taken: Vec<i32> = peekable.peeking_take_while(|&next| next < 5).collect();
+        assert_eq!(taken, vec![1, 2, 3]);
+        assert_eq!(peekable.peek(), Some(&10));
+        assert_eq!(peekable.next(), Some(10));
+        assert_eq!(peekable.next(), Some(4));
+        assert_eq!(peekable.next(), None);
+    }
+
+    #[test]
+    fn peeking_take_while_leaves_the_failing_element_available_for_later() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = iter.fully_peekable();
+        let digits: Vec<i32> = peekable.peeking_take_while(|&next| next < 2).collect();
+        assert_eq!(digits, vec![1]);
+        let rest: Vec<i32> = peekable.peeking_take_while(|&next| next < 10).collect();
+        assert_eq!(rest, vec![2, 3]);
+        assert_eq!(peekable.next(), None);
+    }
+
+    #[test]
+    fn it_returns_elements_from_the_back_like_a_double_ended_iterator() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.next_back(), Some(3));
+        assert_eq!(peekable.next_back(), Some(2));
+        assert_eq!(peekable.next_back(), Some(1));
+        assert_eq!(peekable.next_back(), None);
+    }
+
+    #[test]
+    fn it_meets_in_the_middle_when_consuming_from_both_ends() {
+        let iter = vec![1, 2, 3, 4].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.next(), Some(1));
+        assert_eq!(peekable.next_back(), Some(4));
+        assert_eq!(peekable.next(), Some(2));
+        assert_eq!(peekable.next_back(), Some(3));
+        assert_eq!(peekable.next(), None);
+        assert_eq!(peekable.next_back(), None);
+    }
+
+    #[test]
+    fn it_can_lift_elements_from_the_back_without_advancing() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.lift_back(0), Some(&3));
+        assert_eq!(peekable.lift_back(1), Some(&2));
+        assert_eq!(peekable.lift_back(2), Some(&1));
+        assert_eq!(peekable.lift_back(3), None);
+        assert_eq!(peekable.next_back(), Some(3));
+        assert_eq!(peekable.next_back(), Some(2));
+        assert_eq!(peekable.next_back(), Some(1));
+        assert_eq!(peekable.next_back(), None);
+    }
+
+    #[test]
+    fn it_can_peek_at_the_last_element_without_advancing() {
+        let iter = vec![1, 2].into_iter();
+        let mut peekable = iter.fully_peekable();
+        assert_eq!(peekable.peek_back(), Some(&2));
+        assert_eq!(peekable.next_back(), Some(2));
+        assert_eq!(peekable.peek_back(), Some(&1));
+        assert_eq!(peekable.next_back(), Some(1));
+        assert_eq!(peekable.peek_back(), None);
+        assert_eq!(peekable.next_back(), None);
+    }
+
+    #[test]
+    fn front_and_back_peeks_never_double_yield_the_same_element() {
+        let iter = vec![1, 2, 3].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.peek_back(), Some(&3));
+        assert_eq!(peekable.lift(1), Some(&2));
+        assert_eq!(peekable.lift_back(1), Some(&2));
+        assert_eq!(peekable.next(), Some(1));
+        assert_eq!(peekable.next(), Some(2));
+        assert_eq!(peekable.next_back(), Some(3));
+        assert_eq!(peekable.next(), None);
+        assert_eq!(peekable.next_back(), None);
+    }
+
+    #[test]
+    fn nth_honors_both_front_and_back_buffered_runs() {
+        let iter = vec![1, 2, 3, 4, 5].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.peek_back(), Some(&5));
+        assert_eq!(peekable.nth(1), Some(2));
+        assert_eq!(peekable.next(), Some(3));
+        assert_eq!(peekable.next(), Some(4));
+        assert_eq!(peekable.next(), Some(5));
+        assert_eq!(peekable.next(), None);
+    }
+
+    #[test]
+    fn count_includes_both_front_and_back_buffered_items() {
+        let iter = vec![1, 2, 3, 4, 5].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.peek_back(), Some(&5));
+        assert_eq!(peekable.count(), 5);
+    }
+
+    #[test]
+    fn last_returns_the_true_tail_when_back_buffered() {
+        let iter = vec![1, 2, 3, 4, 5].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.peek_back(), Some(&5));
+        assert_eq!(peekable.last(), Some(5));
+    }
+
+    #[test]
+    fn fold_honors_front_then_middle_then_back_ordering() {
+        let iter = vec![1, 2, 3, 4, 5].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.peek_back(), Some(&5));
+        let order = peekable.fold(Vec::new(), |mut acc, item| {
+            acc.push(item);
+            acc
+        });
+        assert_eq!(order, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn try_fold_short_circuits_while_items_are_buffered() {
+        // `try_fold` isn't overridden (see the `fold` doc comment), so this locks in that the
+        // default, `next`-based fallback still short-circuits correctly with both a front and a
+        // back buffered run in `queue`.
+        let iter = vec![1, 2, 3, 4, 5].into_iter();
+        let mut peekable = FullyPeekableIterator::new(iter);
+        assert_eq!(peekable.peek(), Some(&1));
+        assert_eq!(peekable.peek_back(), Some(&5));
+        assert_eq!(peekable.find(|&x| x == 2), Some(2));
+        assert_eq!(peekable.next(), Some(3));
+        assert_eq!(peekable.next(), Some(4));
+        assert_eq!(peekable.next(), Some(5));
+        assert_eq!(peekable.next(), None);
+    }
 }